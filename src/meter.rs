@@ -19,6 +19,7 @@
 //!
 //! use crate::meter::{Meter, MeterMarker};
 //!
+//! #[derive(Reflect)]
 //! struct HealthMarker {}
 //! impl MeterMarker for HealthMarker {
 //!     type Field = i64;
@@ -39,19 +40,22 @@
 //! }
 //! ```
 //!
-//! Here's an example of how to create a time-based fire damage:
+//! Here's an example of how to create a time-based fire damage that ticks
+//! once a second for five seconds and then removes itself:
 //!
 //! ```rust
 //! use bevy::prelude::*;
 //!
 //! mod meter;
-//! use crate::meter::{Meter, MeterEffect, MeterEffectMarker, MeterMarker};
+//! use crate::meter::{Meter, MeterEffect, MeterEffectMarker, MeterEventsPlugin, MeterMarker};
 //!
+//! #[derive(Reflect)]
 //! struct HealthMarker {}
 //! impl MeterMarker for HealthMarker {
 //!     type Field = i64;
 //! }
 //!
+//! #[derive(Reflect)]
 //! struct FireDamageMarker {}
 //! impl MeterEffectMarker for FireDamageMarker {
 //!     type Marker = HealthMarker;
@@ -61,49 +65,309 @@
 //! type FireDamage = MeterEffect<FireDamageMarker>;
 //!
 //! fn setup_player(mut commands: Commands) {
-//!     commands.spawn((HealthMeter::new_from_max(100), FireDamage::new(-5)));
+//!     commands.spawn((HealthMeter::new_from_max(100), FireDamage::new_timed(-5, Some(5.0), Some(1.0))));
 //! }
 //!
 //! fn main() {
 //!     App::new()
 //!         .insert_resource(Time::<Fixed>::from_seconds(1.0))
+//!         .add_plugins(MeterEventsPlugin::<HealthMarker>::default())
 //!         .add_systems(Startup, setup_player)
 //!         .add_systems(FixedUpdate, FireDamage::apply_effect)
 //!         .run();
 //! }
 //! ```
+use std::any::type_name;
 use std::marker::PhantomData;
 
+use bevy::diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
 use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
-use num_traits::NumAssignOps;
+use num_traits::{NumAssignOps, NumCast, ToPrimitive, Zero};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A [`Meter`] represents some form of expendable resource for an entity. The
 /// typical example of a meter is health and mana. In order to make [`Meter`]s
 /// unique from the Bevy [`Component`] standpoint, a marker must be supplied in
 /// the form of a struct implementing the [`MeterMarker`] trait which also
 /// defines what type the [`Meter`] should track (e.g. [`i64`]).
-#[derive(Component)]
+///
+/// [`Meter`] derives [`Reflect`] so it can round-trip through scenes/saves;
+/// see [`MeterData`] for how `max`/`current` are (de)serialized without
+/// trying to serialize the `PhantomData<T>` marker.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct Meter<T: MeterMarker> {
     /// The maximum amount storable in the bar.
     pub max: T::Field,
-    /// The current amount stored in the bar.
+    /// The current amount stored in the bar. Always kept within `[0, max]`;
+    /// see [`Meter::clamp`].
     pub current: T::Field,
+    /// An optional fraction of `max` (e.g. `0.25`) below which a
+    /// [`MeterThreshold`] event fires as `current` crosses it going down.
+    pub warn_threshold: Option<f32>,
     /// The marker that uniquely defines what kind of meter.
+    #[reflect(ignore)]
     _marker: PhantomData<T>,
 }
 
+/// The serializable shape of a [`Meter<T>`]: just `max`/`current`/
+/// `warn_threshold`, with `T` re-established from context (the caller's
+/// `Meter<T>` type annotation) rather than serialized, since the marker
+/// carries no data of its own.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MeterData<F> {
+    max: F,
+    current: F,
+    warn_threshold: Option<f32>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: MeterMarker> Serialize for Meter<T>
+where
+    T::Field: Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return MeterData {
+            max: self.max,
+            current: self.current,
+            warn_threshold: self.warn_threshold,
+        }
+        .serialize(serializer);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: MeterMarker> Deserialize<'de> for Meter<T>
+where
+    T::Field: Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = MeterData::<T::Field>::deserialize(deserializer)?;
+        return Ok(Self {
+            max: data.max,
+            current: data.current,
+            warn_threshold: data.warn_threshold,
+            _marker: PhantomData,
+        });
+    }
+}
+
+/// Fired when a [`Meter<T>`]'s `current` crosses down to its floor of `0`.
+#[derive(Event)]
+pub struct MeterDepleted<T: MeterMarker> {
+    pub entity: Entity,
+    _marker: PhantomData<T>,
+}
+
+/// Fired when a [`Meter<T>`]'s `current` crosses up to its `max`.
+#[derive(Event)]
+pub struct MeterFull<T: MeterMarker> {
+    pub entity: Entity,
+    _marker: PhantomData<T>,
+}
+
+/// Fired when a [`Meter<T>`]'s `current` crosses down through its configured
+/// `warn_threshold` fraction of `max`.
+#[derive(Event)]
+pub struct MeterThreshold<T: MeterMarker> {
+    pub entity: Entity,
+    /// The `warn_threshold` fraction that was crossed.
+    pub fraction: f32,
+    _marker: PhantomData<T>,
+}
+
+/// Registers [`MeterDepleted<T>`], [`MeterFull<T>`], and [`MeterThreshold<T>`]
+/// with the [`App`] via `add_event`. Bevy panics the first time an
+/// `EventWriter<E>` sends an unregistered event type, so this plugin must be
+/// added for every [`MeterMarker`] before `MeterEffect::apply_effect`,
+/// `MeterRegen::apply_regen`, or [`MeterCommandsExt`] run for it — the same
+/// per-marker registration pattern as [`MeterTypeRegistrationPlugin`].
+pub struct MeterEventsPlugin<T: MeterMarker> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: MeterMarker> Default for MeterEventsPlugin<T> {
+    fn default() -> Self {
+        return Self {
+            _marker: PhantomData,
+        };
+    }
+}
+
+impl<T: MeterMarker> Plugin for MeterEventsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MeterDepleted<T>>()
+            .add_event::<MeterFull<T>>()
+            .add_event::<MeterThreshold<T>>();
+    }
+}
+
+/// The threshold crossings that [`apply_delta`] can detect when it changes a
+/// [`Meter`]'s `current` value.
+enum MeterCrossing {
+    Depleted,
+    Full,
+    Threshold(f32),
+}
+
+/// Applies `delta` to `meter.current`, clamping the result to `[0, max]`, and
+/// returns any threshold(s) crossed as a result (comparing against `current`
+/// before the change). Shared by every system that mutates a [`Meter`] so that
+/// clamping and event detection stay consistent.
+fn apply_delta<T: MeterMarker>(meter: &mut Meter<T>, delta: T::Field) -> Vec<MeterCrossing> {
+    let previous = meter.current;
+    meter.current += delta;
+    meter.clamp();
+    return detect_crossings(meter, previous);
+}
+
+/// Compares a [`Meter`]'s current (already-clamped) value against its
+/// `previous` value and returns any threshold(s) that were crossed. Shared by
+/// [`apply_delta`] and anything else that assigns `current` directly (e.g.
+/// [`MeterCommandsExt::set_meter`]) rather than applying a delta.
+fn detect_crossings<T: MeterMarker>(meter: &Meter<T>, previous: T::Field) -> Vec<MeterCrossing> {
+    let zero = T::Field::zero();
+    let mut crossings = Vec::new();
+    if previous > zero && meter.current <= zero {
+        crossings.push(MeterCrossing::Depleted);
+    }
+    if previous < meter.max && meter.current >= meter.max {
+        crossings.push(MeterCrossing::Full);
+    }
+    if let Some(fraction) = meter.warn_threshold {
+        if let (Some(previous), Some(current), Some(max)) =
+            (previous.to_f64(), meter.current.to_f64(), meter.max.to_f64())
+        {
+            let level = max * fraction as f64;
+            if previous > level && current <= level {
+                crossings.push(MeterCrossing::Threshold(fraction));
+            }
+        }
+    }
+    return crossings;
+}
+
+/// One active application of a [`MeterEffect`]: the amount by which the
+/// meter should be changed, its own timing state, and nothing else. Kept
+/// separate from [`MeterEffect`] itself so that a single component can hold
+/// more than one of these at once (see "stacking" below).
+#[derive(Clone, Copy, Reflect)]
+struct MeterEffectInstance<F> {
+    /// The amount by which the meter should be changed (may be posititve or
+    /// negative).
+    amount: F,
+    /// How long the effect should remain active before removing itself, or
+    /// [`None`] to last until removed manually (the original behavior).
+    duration: Option<f32>,
+    /// How often `amount` should be (re-)applied, or [`None`]/`0.0` to treat
+    /// `amount` as a per-second rate applied continuously, scaled by delta.
+    tick_interval: Option<f32>,
+    /// How much time has elapsed since the effect was added.
+    elapsed: f32,
+    /// With a `tick_interval`, accumulates time between ticks so that an
+    /// interval shorter than a single frame still ticks the right number of
+    /// times. Without one, accumulates the fractional remainder of
+    /// `amount * delta` so sub-unit rates still apply correctly.
+    accumulator: f32,
+}
+
 /// A [`MeterEffect`] represents some type of value change on a meter's
 /// `current` field. This can be used to represent things like one-time hits or
 /// some mana usage from casing a spell, or perhaps debuffs.
-#[derive(Component)]
+///
+/// An effect may also be timed: given a `tick_interval`, `amount` is applied
+/// once per interval rather than continuously as a per-second rate scaled by
+/// delta, and given a `duration`, the
+/// effect removes itself once that much time has elapsed. This is what turns
+/// a one-shot hit into a damage-over-time or a temporary buff/debuff, all
+/// through the same component.
+///
+/// [`MeterEffect`] holds a stack of [`MeterEffectInstance`]s rather than a
+/// single one, so that multiple effects sharing the same
+/// [`MeterEffectMarker`] on one entity (e.g. a second stack of poison) each
+/// tick independently instead of one replacing the other. Use
+/// [`MeterEffect::stack`] to add another instance to an entity that may
+/// already have this component.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", reflect(Serialize, Deserialize))]
 pub struct MeterEffect<T: MeterEffectMarker> {
-    /// The amount by which the meter should be changed (may be posititve or
-    /// negative).
-    amount: <T::Marker as MeterMarker>::Field,
+    instances: Vec<MeterEffectInstance<<T::Marker as MeterMarker>::Field>>,
+    #[reflect(ignore)]
     _marker: PhantomData<T>,
 }
 
+/// The serializable shape of a [`MeterEffectInstance<F>`]: `amount` plus its
+/// timing state.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MeterEffectInstanceData<F> {
+    amount: F,
+    duration: Option<f32>,
+    tick_interval: Option<f32>,
+    elapsed: f32,
+    accumulator: f32,
+}
+
+/// The serializable shape of a [`MeterEffect<T>`]: its stack of instances,
+/// with `T` re-established from context the same way as [`MeterData`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct MeterEffectData<F> {
+    instances: Vec<MeterEffectInstanceData<F>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: MeterEffectMarker> Serialize for MeterEffect<T>
+where
+    <T::Marker as MeterMarker>::Field: Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return MeterEffectData {
+            instances: self
+                .instances
+                .iter()
+                .map(|instance| MeterEffectInstanceData {
+                    amount: instance.amount,
+                    duration: instance.duration,
+                    tick_interval: instance.tick_interval,
+                    elapsed: instance.elapsed,
+                    accumulator: instance.accumulator,
+                })
+                .collect(),
+        }
+        .serialize(serializer);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: MeterEffectMarker> Deserialize<'de> for MeterEffect<T>
+where
+    <T::Marker as MeterMarker>::Field: Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = MeterEffectData::<<T::Marker as MeterMarker>::Field>::deserialize(deserializer)?;
+        return Ok(Self {
+            instances: data
+                .instances
+                .into_iter()
+                .map(|instance| MeterEffectInstance {
+                    amount: instance.amount,
+                    duration: instance.duration,
+                    tick_interval: instance.tick_interval,
+                    elapsed: instance.elapsed,
+                    accumulator: instance.accumulator,
+                })
+                .collect(),
+            _marker: PhantomData,
+        });
+    }
+}
+
 /// This custom query is used for querying an entity which has a meter and some
 /// active effect.
 ///
@@ -127,41 +391,765 @@ impl<T: MeterMarker> Meter<T> {
         return Self {
             max: max,
             current: max,
+            warn_threshold: None,
             _marker: PhantomData,
         };
     }
+
+    /// Configure a fraction of `max` (e.g. `0.25` for 25%) that, when
+    /// `current` crosses it going down, fires a [`MeterThreshold`] event.
+    pub fn with_warn_threshold(mut self, fraction: f32) -> Self {
+        self.warn_threshold = Some(fraction);
+        return self;
+    }
+
+    /// Clamp `current` into the inclusive range `[0, max]`.
+    fn clamp(&mut self) {
+        let zero = T::Field::zero();
+        if self.current > self.max {
+            self.current = self.max;
+        } else if self.current < zero {
+            self.current = zero;
+        }
+    }
 }
 
 impl<T: MeterEffectMarker> MeterEffect<T> {
     /// Create a new [`Self`] given an amount by which to change the associated
-    /// meter.
+    /// meter. The effect is untimed: `amount` is applied continuously as a
+    /// per-second rate, scaled by delta, until removed manually.
     pub fn new(amount: <T::Marker as MeterMarker>::Field) -> Self {
+        return Self::new_timed(amount, None, None);
+    }
+
+    /// Create a new [`Self`] with an optional `duration`, after which the
+    /// effect removes itself, and an optional `tick_interval`, which controls
+    /// how often `amount` is applied (rather than every frame). Starts with a
+    /// single instance in its stack; call [`Self::stack`] to add more once
+    /// this is on an entity.
+    pub fn new_timed(
+        amount: <T::Marker as MeterMarker>::Field,
+        duration: Option<f32>,
+        tick_interval: Option<f32>,
+    ) -> Self {
         return Self {
+            instances: vec![MeterEffectInstance {
+                amount: amount,
+                duration: duration,
+                tick_interval: tick_interval,
+                elapsed: 0.0,
+                accumulator: 0.0,
+            }],
+            _marker: PhantomData,
+        };
+    }
+
+    /// Add another instance to this effect's stack (e.g. a second stack of
+    /// poison), which ticks its own `elapsed`/`accumulator` independently of
+    /// any instances already present. This is how same-marker effects stack
+    /// despite [`MeterEffect`] being a single [`Component`]: inserting a
+    /// second `MeterEffect<T>` on the same entity would otherwise just
+    /// replace the first outright.
+    pub fn stack(
+        &mut self,
+        amount: <T::Marker as MeterMarker>::Field,
+        duration: Option<f32>,
+        tick_interval: Option<f32>,
+    ) -> &mut Self {
+        self.instances.push(MeterEffectInstance {
             amount: amount,
+            duration: duration,
+            tick_interval: tick_interval,
+            elapsed: 0.0,
+            accumulator: 0.0,
+        });
+        return self;
+    }
+
+    /// The Bevy system used to apply a meter effect. Each frame, advances
+    /// every instance in the effect's stack using [`Time`]: each instance's
+    /// `amount` is applied once per its `tick_interval`, or continuously
+    /// scaled by delta if no interval was given (so the total effect doesn't
+    /// depend on framerate), clamped to `[0, max]` and firing
+    /// [`MeterDepleted`]/[`MeterFull`]/[`MeterThreshold`] as `current`
+    /// crosses those bounds. Once an instance's `elapsed` reaches its
+    /// `duration`, that instance is dropped from the stack; once the stack
+    /// is empty, the whole component removes itself via [`Commands`].
+    /// Instances stacked via [`Self::stack`] tick independently of each
+    /// other, as do distinct [`MeterEffectMarker`]s, which each get their own
+    /// generic instance of this system.
+    pub fn apply_effect(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut query: Query<(Entity, MeterEffectQuery<T>)>,
+        mut depleted_events: EventWriter<MeterDepleted<T::Marker>>,
+        mut full_events: EventWriter<MeterFull<T::Marker>>,
+        mut threshold_events: EventWriter<MeterThreshold<T::Marker>>,
+    ) {
+        let delta = time.delta_secs();
+        for (entity, MeterEffectQueryItem { mut meter, mut effect }) in query.iter_mut() {
+            let mut crossings = Vec::new();
+            effect.instances.retain_mut(|instance| {
+                match instance.tick_interval {
+                    Some(interval) if interval > 0.0 => {
+                        instance.accumulator += delta;
+                        while instance.accumulator >= interval {
+                            instance.accumulator -= interval;
+                            crossings.extend(apply_delta(&mut *meter, instance.amount));
+                        }
+                    }
+                    _ => {
+                        // No interval configured: treat `amount` as a per-second
+                        // rate and apply it scaled by delta, carrying the
+                        // fractional remainder forward in `accumulator` the same
+                        // way `MeterRegen::apply_regen` does, so the total effect
+                        // over time doesn't depend on framerate.
+                        if let Some(amount) = instance.amount.to_f32() {
+                            instance.accumulator += amount * delta;
+                            let whole = instance.accumulator.trunc();
+                            if whole != 0.0 {
+                                if let Some(scaled) =
+                                    <<T::Marker as MeterMarker>::Field as NumCast>::from(whole)
+                                {
+                                    crossings.extend(apply_delta(&mut *meter, scaled));
+                                }
+                                instance.accumulator -= whole;
+                            }
+                        }
+                    }
+                }
+
+                if let Some(duration) = instance.duration {
+                    instance.elapsed += delta;
+                    if instance.elapsed >= duration {
+                        return false;
+                    }
+                }
+                return true;
+            });
+
+            for crossing in crossings {
+                match crossing {
+                    MeterCrossing::Depleted => {
+                        depleted_events.send(MeterDepleted {
+                            entity: entity,
+                            _marker: PhantomData,
+                        });
+                    }
+                    MeterCrossing::Full => {
+                        full_events.send(MeterFull {
+                            entity: entity,
+                            _marker: PhantomData,
+                        });
+                    }
+                    MeterCrossing::Threshold(fraction) => {
+                        threshold_events.send(MeterThreshold {
+                            entity: entity,
+                            fraction: fraction,
+                            _marker: PhantomData,
+                        });
+                    }
+                }
+            }
+
+            if effect.instances.is_empty() {
+                commands.entity(entity).remove::<MeterEffect<T>>();
+            }
+        }
+    }
+}
+
+/// A [`MeterRegen`] describes a steady, continuous rate of change (units per
+/// second, negative for decay) applied to a [`Meter<T>`]'s `current` field.
+/// Unlike a [`MeterEffect`], which represents a discrete, markered change, a
+/// [`MeterRegen`] is a single, ongoing background rate such as mana
+/// regeneration or poison-style decay.
+///
+/// `delay_after_change` pauses regeneration for that many seconds after
+/// `current` changes from some other source (e.g. a [`MeterEffect`] hit),
+/// which is the usual "regen doesn't kick in right after you've been hit"
+/// behavior.
+#[derive(Component)]
+pub struct MeterRegen<T: MeterMarker> {
+    /// Units per second applied to `current`. May be negative for decay.
+    pub rate: f32,
+    /// How long regeneration pauses after `current` changes from a source
+    /// other than this component.
+    pub delay_after_change: f32,
+    /// Time remaining before regeneration resumes.
+    cooldown: f32,
+    /// The `current` value last observed by this component, used to detect
+    /// changes made by other systems (e.g. [`MeterEffect`]).
+    last_seen: Option<T::Field>,
+    /// Accumulates the fractional part of `rate * delta` between frames so
+    /// that sub-unit rates still apply correctly to integer `Field` types,
+    /// carrying the remainder forward rather than truncating it away.
+    remainder: f32,
+    _marker: PhantomData<T>,
+}
+
+impl<T: MeterMarker> MeterRegen<T> {
+    /// Create a new [`Self`] with the given `rate` (units per second,
+    /// negative for decay) and no delay after the meter changes.
+    pub fn new(rate: f32) -> Self {
+        return Self::new_with_delay(rate, 0.0);
+    }
+
+    /// Create a new [`Self`] that pauses for `delay_after_change` seconds
+    /// after `current` changes from some other source.
+    pub fn new_with_delay(rate: f32, delay_after_change: f32) -> Self {
+        return Self {
+            rate: rate,
+            delay_after_change: delay_after_change,
+            cooldown: 0.0,
+            last_seen: None,
+            remainder: 0.0,
+            _marker: PhantomData,
+        };
+    }
+
+    /// The Bevy system used to apply meter regeneration/degeneration. Each
+    /// frame, if `current` changed since it was last observed (from some
+    /// source other than this system), the regen cooldown is (re-)started.
+    /// Otherwise, `rate * delta` accumulates in `remainder`, and whole units
+    /// are applied to `current` (clamped, firing the usual threshold events)
+    /// as soon as they accrue.
+    pub fn apply_regen(
+        mut query: Query<(Entity, &mut Meter<T>, &mut MeterRegen<T>)>,
+        time: Res<Time>,
+        mut depleted_events: EventWriter<MeterDepleted<T>>,
+        mut full_events: EventWriter<MeterFull<T>>,
+        mut threshold_events: EventWriter<MeterThreshold<T>>,
+    ) {
+        let delta = time.delta_secs();
+        for (entity, mut meter, mut regen) in query.iter_mut() {
+            if let Some(last_seen) = regen.last_seen {
+                if last_seen != meter.current {
+                    regen.cooldown = regen.delay_after_change;
+                    regen.remainder = 0.0;
+                }
+            }
+
+            let mut crossings = Vec::new();
+            if regen.cooldown > 0.0 {
+                regen.cooldown = (regen.cooldown - delta).max(0.0);
+            } else {
+                regen.remainder += regen.rate * delta;
+                let whole = regen.remainder.trunc();
+                if whole != 0.0 {
+                    if let Some(amount) = <T::Field as NumCast>::from(whole) {
+                        crossings.extend(apply_delta(&mut *meter, amount));
+                    }
+                    regen.remainder -= whole;
+                }
+            }
+
+            for crossing in crossings {
+                match crossing {
+                    MeterCrossing::Depleted => {
+                        depleted_events.send(MeterDepleted {
+                            entity: entity,
+                            _marker: PhantomData,
+                        });
+                    }
+                    MeterCrossing::Full => {
+                        full_events.send(MeterFull {
+                            entity: entity,
+                            _marker: PhantomData,
+                        });
+                    }
+                    MeterCrossing::Threshold(fraction) => {
+                        threshold_events.send(MeterThreshold {
+                            entity: entity,
+                            fraction: fraction,
+                            _marker: PhantomData,
+                        });
+                    }
+                }
+            }
+
+            regen.last_seen = Some(meter.current);
+        }
+    }
+}
+
+/// An opt-in [`Plugin`] that registers a [`Diagnostic`] per [`MeterMarker`]
+/// and, each frame, pushes the sum and average of `current` plus the average
+/// `current / max` ratio across every [`Meter<T>`] into Bevy's
+/// `DiagnosticsStore`. This lets health/mana pools be graphed with the
+/// standard Bevy diagnostics tooling instead of hand-rolled logging.
+pub struct MeterDiagnosticsPlugin<T: MeterMarker> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: MeterMarker> Default for MeterDiagnosticsPlugin<T> {
+    fn default() -> Self {
+        return Self {
             _marker: PhantomData,
         };
     }
+}
+
+impl<T: MeterMarker> MeterDiagnosticsPlugin<T> {
+    /// The [`DiagnosticPath`] for the sum of `current` across every
+    /// [`Meter<T>`].
+    pub fn current_sum_path() -> DiagnosticPath {
+        return DiagnosticPath::new(format!("meter/{}/current_sum", type_name::<T>()));
+    }
+
+    /// The [`DiagnosticPath`] for the average `current` across every
+    /// [`Meter<T>`].
+    pub fn current_average_path() -> DiagnosticPath {
+        return DiagnosticPath::new(format!("meter/{}/current_average", type_name::<T>()));
+    }
 
-    /// The Bevy system used to apply a meter effect. Note that the effect will
-    /// be neither removed nor timed.
-    pub fn apply_effect(mut query: Query<MeterEffectQuery<T>>) {
-        for MeterEffectQueryItem { mut meter, effect } in query.iter_mut() {
-            meter.current += effect.amount;
+    /// The [`DiagnosticPath`] for the average `current / max` ratio across
+    /// every [`Meter<T>`].
+    pub fn current_ratio_path() -> DiagnosticPath {
+        return DiagnosticPath::new(format!("meter/{}/current_ratio", type_name::<T>()));
+    }
+
+    /// Measures the aggregate `current`/`max` values across every
+    /// [`Meter<T>`] and pushes them into the [`Diagnostics`] writer. Uses a
+    /// read-only query plus a dedicated writer so this runs in
+    /// parallel-friendly fashion alongside the rest of the app.
+    fn measure(query: Query<&Meter<T>>, mut diagnostics: Diagnostics) {
+        let mut sum = 0.0;
+        let mut ratio_sum = 0.0;
+        let mut count = 0usize;
+        for meter in &query {
+            let current = meter.current.to_f64().unwrap_or(0.0);
+            let max = meter.max.to_f64().unwrap_or(0.0);
+            sum += current;
+            if max != 0.0 {
+                ratio_sum += current / max;
+            }
+            count += 1;
+        }
+
+        diagnostics.add_measurement(&Self::current_sum_path(), || sum);
+        if count > 0 {
+            diagnostics.add_measurement(&Self::current_average_path(), || sum / count as f64);
+            diagnostics.add_measurement(&Self::current_ratio_path(), || ratio_sum / count as f64);
         }
     }
 }
 
+impl<T: MeterMarker> Plugin for MeterDiagnosticsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::current_sum_path()))
+            .register_diagnostic(Diagnostic::new(Self::current_average_path()))
+            .register_diagnostic(Diagnostic::new(Self::current_ratio_path()))
+            .add_systems(Update, Self::measure);
+    }
+}
+
 /// A [`MeterMarker`] can be used to create new, unique [`Meter`]s, each of
 /// which can be used in Bevy as its own component.
-pub trait MeterMarker: Send + Sync {
+///
+/// Requires [`TypePath`] (and `Field: Reflect + FromReflect + TypePath`, plus
+/// `Field: Serialize + Deserialize` under the `serde` feature) so that
+/// [`Meter<T>`] can derive [`Reflect`] and actually be registered with the
+/// [`TypeRegistry`] via [`MeterTypeRegistrationPlugin`] — `#[derive(Reflect)]`
+/// on a generic struct only gets `GetTypeRegistration` if the generic
+/// parameter's fields meet these bounds too.
+#[cfg(not(feature = "serde"))]
+pub trait MeterMarker: Send + Sync + 'static + TypePath {
+    /// The type of the meter's fields, typically [`i64`] or [`i32`].
+    type Field: Copy
+        + Send
+        + Sync
+        + NumAssignOps
+        + PartialEq
+        + PartialOrd
+        + Zero
+        + ToPrimitive
+        + NumCast
+        + Reflect
+        + FromReflect
+        + TypePath;
+}
+
+/// Requires [`TypePath`] (and `Field: Reflect + FromReflect + TypePath`, plus
+/// `Field: Serialize + Deserialize` under the `serde` feature) so that
+/// [`Meter<T>`] can derive [`Reflect`] and actually be registered with the
+/// [`TypeRegistry`] via [`MeterTypeRegistrationPlugin`] — `#[derive(Reflect)]`
+/// on a generic struct only gets `GetTypeRegistration` if the generic
+/// parameter's fields meet these bounds too.
+#[cfg(feature = "serde")]
+pub trait MeterMarker: Send + Sync + 'static + TypePath {
     /// The type of the meter's fields, typically [`i64`] or [`i32`].
-    type Field: Copy + Send + Sync + NumAssignOps;
+    type Field: Copy
+        + Send
+        + Sync
+        + NumAssignOps
+        + PartialEq
+        + PartialOrd
+        + Zero
+        + ToPrimitive
+        + NumCast
+        + Reflect
+        + FromReflect
+        + TypePath
+        + Serialize
+        + for<'de> Deserialize<'de>;
 }
 
 /// A [`MeterEffectMarker`] can be used to create new, unique [`MeterEffect`]s,
 /// each of which can be used in Bevy as its own component.
-pub trait MeterEffectMarker: Send + Sync {
+pub trait MeterEffectMarker: Send + Sync + TypePath {
     /// The type of the meter effect's meter marker (which will be used to
     /// define/query for the corresponding meter component).
     type Marker: MeterMarker;
 }
+
+/// Registers [`Meter<T>`] with Bevy's [`TypeRegistry`] so scene/save
+/// round-trips (e.g. `DynamicScene`) can see it. Takes the marker as a
+/// generic, the same way the rest of this module does, since the registry
+/// has no other way to learn which specific meter type to register.
+pub struct MeterTypeRegistrationPlugin<T: MeterMarker> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: MeterMarker> Default for MeterTypeRegistrationPlugin<T> {
+    fn default() -> Self {
+        return Self {
+            _marker: PhantomData,
+        };
+    }
+}
+
+impl<T: MeterMarker> Plugin for MeterTypeRegistrationPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Meter<T>>();
+    }
+}
+
+/// Registers [`MeterEffect<T>`] with Bevy's [`TypeRegistry`], mirroring
+/// [`MeterTypeRegistrationPlugin`].
+pub struct MeterEffectTypeRegistrationPlugin<T: MeterEffectMarker> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: MeterEffectMarker> Default for MeterEffectTypeRegistrationPlugin<T> {
+    fn default() -> Self {
+        return Self {
+            _marker: PhantomData,
+        };
+    }
+}
+
+impl<T: MeterEffectMarker> Plugin for MeterEffectTypeRegistrationPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MeterEffect<T>>();
+    }
+}
+
+/// Sends the given `crossings` as their corresponding typed events through
+/// `world` directly, for contexts (like [`MeterCommandsExt`]) that have
+/// [`World`] access instead of an [`EventWriter`].
+fn send_crossings<T: MeterMarker>(world: &mut World, entity: Entity, crossings: Vec<MeterCrossing>) {
+    for crossing in crossings {
+        match crossing {
+            MeterCrossing::Depleted => {
+                world.send_event(MeterDepleted::<T> {
+                    entity: entity,
+                    _marker: PhantomData,
+                });
+            }
+            MeterCrossing::Full => {
+                world.send_event(MeterFull::<T> {
+                    entity: entity,
+                    _marker: PhantomData,
+                });
+            }
+            MeterCrossing::Threshold(fraction) => {
+                world.send_event(MeterThreshold::<T> {
+                    entity: entity,
+                    fraction: fraction,
+                    _marker: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+/// A [`Commands`] extension for ergonomic meter mutation, following this
+/// module's existing pattern of giving gameplay code generic-marker methods
+/// instead of requiring a bespoke [`MeterEffectQuery`] system or manually
+/// spawning a [`MeterEffect`] component for a single change.
+pub trait MeterCommandsExt {
+    /// Apply `amount` to `entity`'s `Meter<T::Marker>` once, immediately,
+    /// clamping to `[0, max]` and firing the usual threshold events. This is
+    /// the one-shot equivalent of spawning a `MeterEffect<T>`.
+    fn apply_meter_effect<T: MeterEffectMarker>(
+        &mut self,
+        entity: Entity,
+        amount: <T::Marker as MeterMarker>::Field,
+    ) -> &mut Self;
+
+    /// Set `entity`'s `Meter<T>` `current` directly, clamping to `[0, max]`
+    /// and firing the usual threshold events.
+    fn set_meter<T: MeterMarker>(&mut self, entity: Entity, current: T::Field) -> &mut Self;
+}
+
+impl MeterCommandsExt for Commands<'_, '_> {
+    fn apply_meter_effect<T: MeterEffectMarker>(
+        &mut self,
+        entity: Entity,
+        amount: <T::Marker as MeterMarker>::Field,
+    ) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            let Some(mut meter) = world.get_mut::<Meter<T::Marker>>(entity) else {
+                return;
+            };
+            let crossings = apply_delta(&mut *meter, amount);
+            send_crossings::<T::Marker>(world, entity, crossings);
+        });
+        return self;
+    }
+
+    fn set_meter<T: MeterMarker>(&mut self, entity: Entity, current: T::Field) -> &mut Self {
+        self.queue(move |world: &mut World| {
+            let Some(mut meter) = world.get_mut::<Meter<T>>(entity) else {
+                return;
+            };
+            let previous = meter.current;
+            meter.current = current;
+            meter.clamp();
+            let crossings = detect_crossings(&*meter, previous);
+            send_crossings::<T>(world, entity, crossings);
+        });
+        return self;
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::diagnostic::DiagnosticsStore;
+
+    use super::*;
+
+    #[derive(Reflect)]
+    struct HealthMarker;
+    impl MeterMarker for HealthMarker {
+        type Field = i64;
+    }
+
+    #[derive(Reflect)]
+    struct ManaMarker;
+    impl MeterMarker for ManaMarker {
+        type Field = i64;
+    }
+
+    #[derive(Reflect)]
+    struct PoisonMarker;
+    impl MeterEffectMarker for PoisonMarker {
+        type Marker = HealthMarker;
+    }
+
+    type HealthMeter = Meter<HealthMarker>;
+    type ManaMeter = Meter<ManaMarker>;
+    type PoisonEffect = MeterEffect<PoisonMarker>;
+
+    #[test]
+    fn players_meters_survive_a_serialize_clear_deserialize_cycle() {
+        let mut world = World::new();
+        let player = world
+            .spawn((
+                HealthMeter::new_from_max(100).with_warn_threshold(0.25),
+                ManaMeter::new_from_max(50),
+            ))
+            .id();
+
+        let health_save =
+            serde_json::to_string(world.get::<HealthMeter>(player).unwrap()).unwrap();
+        let mana_save = serde_json::to_string(world.get::<ManaMeter>(player).unwrap()).unwrap();
+
+        world.despawn(player);
+
+        let restored_health: HealthMeter = serde_json::from_str(&health_save).unwrap();
+        let restored_mana: ManaMeter = serde_json::from_str(&mana_save).unwrap();
+        let restored_player = world.spawn((restored_health, restored_mana)).id();
+
+        let health = world.get::<HealthMeter>(restored_player).unwrap();
+        assert_eq!(health.max, 100);
+        assert_eq!(health.current, 100);
+        assert_eq!(health.warn_threshold, Some(0.25));
+
+        let mana = world.get::<ManaMeter>(restored_player).unwrap();
+        assert_eq!(mana.max, 50);
+        assert_eq!(mana.current, 50);
+    }
+
+    #[test]
+    fn meter_effect_survives_a_serialize_clear_deserialize_cycle() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(PoisonEffect::new_timed(-5, Some(10.0), Some(2.0)))
+            .id();
+
+        let save = serde_json::to_string(world.get::<PoisonEffect>(entity).unwrap()).unwrap();
+
+        world.despawn(entity);
+
+        let restored: PoisonEffect = serde_json::from_str(&save).unwrap();
+        let restored_entity = world.spawn(restored).id();
+
+        let effect = world.get::<PoisonEffect>(restored_entity).unwrap();
+        assert_eq!(effect.instances.len(), 1);
+        let instance = &effect.instances[0];
+        assert_eq!(instance.amount, -5);
+        assert_eq!(instance.duration, Some(10.0));
+        assert_eq!(instance.tick_interval, Some(2.0));
+        assert_eq!(instance.elapsed, 0.0);
+        assert_eq!(instance.accumulator, 0.0);
+    }
+
+    #[test]
+    fn stacked_meter_effects_keep_separate_instances() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(PoisonEffect::new_timed(-5, Some(10.0), Some(2.0)))
+            .id();
+
+        world
+            .get_mut::<PoisonEffect>(entity)
+            .unwrap()
+            .stack(-3, Some(4.0), None);
+
+        let effect = world.get::<PoisonEffect>(entity).unwrap();
+        assert_eq!(effect.instances.len(), 2);
+        assert_eq!(effect.instances[0].amount, -5);
+        assert_eq!(effect.instances[0].duration, Some(10.0));
+        assert_eq!(effect.instances[1].amount, -3);
+        assert_eq!(effect.instances[1].duration, Some(4.0));
+    }
+
+    #[test]
+    fn detect_crossings_reports_each_bound_crossing_once() {
+        let mut meter = HealthMeter::new_from_max(100).with_warn_threshold(0.25);
+
+        // Crossing down through the warn threshold.
+        meter.current = 20;
+        let crossings = detect_crossings(&meter, 30);
+        assert_eq!(crossings.len(), 1);
+        assert!(matches!(crossings[0], MeterCrossing::Threshold(fraction) if fraction == 0.25));
+
+        // Pinned at zero across multiple frames: depletion should only be
+        // reported the frame it's actually crossed, not every frame after.
+        meter.current = 0;
+        let first = detect_crossings(&meter, 5);
+        assert_eq!(first.len(), 1);
+        assert!(matches!(first[0], MeterCrossing::Depleted));
+        let second = detect_crossings(&meter, 0);
+        assert!(second.is_empty());
+
+        // Crossing back up to max.
+        meter.current = 100;
+        let crossings = detect_crossings(&meter, 50);
+        assert_eq!(crossings.len(), 1);
+        assert!(matches!(crossings[0], MeterCrossing::Full));
+    }
+
+    #[test]
+    fn meter_regen_pauses_after_external_change_and_accumulates_fractional_remainder() {
+        let mut app = App::new();
+        app.insert_resource(Time::default());
+        app.add_plugins(MeterEventsPlugin::<HealthMarker>::default());
+        app.add_systems(Update, MeterRegen::<HealthMarker>::apply_regen);
+
+        let mut meter = HealthMeter::new_from_max(100);
+        meter.current = 40;
+        let entity = app
+            .world_mut()
+            .spawn((meter, MeterRegen::<HealthMarker>::new_with_delay(2.0, 1.0)))
+            .id();
+
+        let tick = |app: &mut App, seconds: f32| -> i64 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(seconds));
+            app.update();
+            return app.world().get::<HealthMeter>(entity).unwrap().current;
+        };
+
+        assert_eq!(tick(&mut app, 0.3), 40); // remainder accumulating, nothing whole yet
+        assert_eq!(tick(&mut app, 0.3), 41); // 0.6 + 0.6 = 1.2 -> one whole unit applied
+
+        // Simulate an external change (e.g. a hit) resetting the cooldown.
+        app.world_mut().get_mut::<HealthMeter>(entity).unwrap().current = 20;
+
+        assert_eq!(tick(&mut app, 0.5), 20); // regen paused during the delay
+        assert_eq!(tick(&mut app, 0.5), 20); // delay just elapsed, no burst this frame
+        assert_eq!(tick(&mut app, 0.3), 20); // remainder accumulating again, nothing whole yet
+        assert_eq!(tick(&mut app, 0.3), 21); // regen resumed and accrued a whole unit
+    }
+
+    #[test]
+    fn meter_diagnostics_plugin_reports_sum_average_and_ratio() {
+        let mut app = App::new();
+        app.add_plugins(MeterDiagnosticsPlugin::<HealthMarker>::default());
+
+        app.world_mut().spawn(HealthMeter::new_from_max(100));
+        let mut half = HealthMeter::new_from_max(100);
+        half.current = 50;
+        app.world_mut().spawn(half);
+
+        app.update();
+
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        let sum = diagnostics
+            .get(&MeterDiagnosticsPlugin::<HealthMarker>::current_sum_path())
+            .and_then(Diagnostic::value)
+            .unwrap();
+        assert_eq!(sum, 150.0);
+
+        let average = diagnostics
+            .get(&MeterDiagnosticsPlugin::<HealthMarker>::current_average_path())
+            .and_then(Diagnostic::value)
+            .unwrap();
+        assert_eq!(average, 75.0);
+
+        let ratio = diagnostics
+            .get(&MeterDiagnosticsPlugin::<HealthMarker>::current_ratio_path())
+            .and_then(Diagnostic::value)
+            .unwrap();
+        assert!((ratio - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn commands_ext_apply_meter_effect_and_set_meter_mutate_the_meter() {
+        let mut apply_app = App::new();
+        apply_app.add_plugins(MeterEventsPlugin::<HealthMarker>::default());
+        let entity = apply_app
+            .world_mut()
+            .spawn(HealthMeter::new_from_max(100))
+            .id();
+        apply_app.add_systems(Update, move |mut commands: Commands| {
+            commands.apply_meter_effect::<PoisonMarker>(entity, -60);
+        });
+        apply_app.update();
+        assert_eq!(
+            apply_app.world().get::<HealthMeter>(entity).unwrap().current,
+            40
+        );
+
+        let mut set_app = App::new();
+        set_app.add_plugins(MeterEventsPlugin::<HealthMarker>::default());
+        let entity = set_app
+            .world_mut()
+            .spawn(HealthMeter::new_from_max(100))
+            .id();
+        set_app.add_systems(Update, move |mut commands: Commands| {
+            commands.set_meter::<HealthMarker>(entity, 250);
+        });
+        set_app.update();
+        assert_eq!(
+            set_app.world().get::<HealthMeter>(entity).unwrap().current,
+            100
+        );
+    }
+}